@@ -0,0 +1,79 @@
+//! `expect` is a small assertion library with an RSpec-style matcher API.
+//!
+//! ```
+//! use expect::{expect, matchers::equal};
+//!
+//! expect(&"foo").to(equal("foo"));
+//! expect(&"foo").not_to(equal("bar"));
+//! ```
+
+pub mod matchers;
+
+/// A predicate that can be checked against a value with [`expect`].
+///
+/// Implementors only need to provide [`match_value`](Matcher::match_value),
+/// [`failure_message`](Matcher::failure_message) and
+/// [`negated_failure_message`](Matcher::negated_failure_message); [`and`](Matcher::and)
+/// and [`or`](Matcher::or) are provided for composing matchers together.
+pub trait Matcher<T> {
+    /// Returns whether `actual` satisfies this matcher.
+    fn match_value(&self, actual: &T) -> bool;
+
+    /// The message shown when `expect(...).to(matcher)` fails.
+    fn failure_message(&self, actual: &T) -> String;
+
+    /// The message shown when `expect(...).not_to(matcher)` fails.
+    fn negated_failure_message(&self, actual: &T) -> String;
+
+    /// Combines this matcher with `other`, matching only when both do.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use expect::{expect, matchers::collection::contain, Matcher};
+    /// expect(&vec![1, 2, 3]).to(contain(1).and(contain(3)));
+    /// ```
+    fn and<M: Matcher<T>>(self, other: M) -> matchers::combinators::AndMatcher<T, Self, M>
+    where
+        Self: Sized,
+    {
+        matchers::combinators::AndMatcher::new(self, other)
+    }
+
+    /// Combines this matcher with `other`, matching when either does.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use expect::{expect, matchers::equal, Matcher};
+    /// expect(&1).to(equal(1).or(equal(2)));
+    /// ```
+    fn or<M: Matcher<T>>(self, other: M) -> matchers::combinators::OrMatcher<T, Self, M>
+    where
+        Self: Sized,
+    {
+        matchers::combinators::OrMatcher::new(self, other)
+    }
+}
+
+pub fn expect<T>(actual: &T) -> Expectation<'_, T> {
+    Expectation { actual }
+}
+
+pub struct Expectation<'a, T> {
+    actual: &'a T,
+}
+
+impl<'a, T> Expectation<'a, T> {
+    pub fn to<M: Matcher<T>>(&self, matcher: M) {
+        if !matcher.match_value(self.actual) {
+            panic!("\n{}\n", matcher.failure_message(self.actual));
+        }
+    }
+
+    pub fn not_to<M: Matcher<T>>(&self, matcher: M) {
+        if matcher.match_value(self.actual) {
+            panic!("\n{}\n", matcher.negated_failure_message(self.actual));
+        }
+    }
+}
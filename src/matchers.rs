@@ -1,10 +1,50 @@
 pub mod collection;
+pub mod combinators;
+pub(crate) mod diff;
+pub mod numeric;
 pub mod option;
-pub mod path;
 pub mod result;
 pub mod string;
 
 use crate::Matcher;
+use diff::diff;
+use std::marker::PhantomData;
+
+/// Matches any value.
+///
+/// Useful as a placeholder inner matcher when only the shape of a value
+/// matters, e.g. `be_some(anything())` to assert that a value is `Some`
+/// without constraining what it contains.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::anything};
+/// expect(&42).to(anything());
+/// ```
+pub fn anything<T>() -> AnythingMatcher<T> {
+    AnythingMatcher {
+        phantom: PhantomData,
+    }
+}
+
+pub struct AnythingMatcher<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: std::fmt::Debug> Matcher<T> for AnythingMatcher<T> {
+    fn match_value(&self, _actual: &T) -> bool {
+        true
+    }
+
+    fn failure_message(&self, actual: &T) -> String {
+        format!("\tExpected:\n\t\t{:?}\n\tto match anything (unreachable)", actual)
+    }
+
+    fn negated_failure_message(&self, actual: &T) -> String {
+        format!("\tExpected:\n\t\t{:?}\n\tnot to match anything", actual)
+    }
+}
 
 /// Matches if `expected` is equal to the actual value.
 ///
@@ -29,9 +69,13 @@ impl<E: std::fmt::Debug, A: PartialEq<E> + std::fmt::Debug> Matcher<A> for Equal
     }
 
     fn failure_message(&self, actual: &A) -> String {
-        format!(
-            "\tExpected:\n\t\t{:?}\n\tto equal:\n\t\t{:?}",
-            actual, self.expected,
+        with_diff(
+            format!(
+                "\tExpected:\n\t\t{:?}\n\tto equal:\n\t\t{:?}",
+                actual, self.expected,
+            ),
+            actual,
+            &self.expected,
         )
     }
 
@@ -43,11 +87,42 @@ impl<E: std::fmt::Debug, A: PartialEq<E> + std::fmt::Debug> Matcher<A> for Equal
     }
 }
 
+/// Indents every line of `message` by one more level, so a delegated or
+/// combined sub-matcher message nests clearly underneath the outer one.
+pub(crate) fn indent(message: &str) -> String {
+    message
+        .lines()
+        .map(|line| format!("\t{}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Appends an edit-distance diff between the `{:?}` representations of
+/// `actual` and `expected` to `message`, when one can be computed within the
+/// banding threshold. Falls back to returning `message` unchanged otherwise,
+/// so a single differing token doesn't get lost in two full paragraphs.
+pub(crate) fn with_diff<A: std::fmt::Debug, E: std::fmt::Debug>(
+    message: String,
+    actual: &A,
+    expected: &E,
+) -> String {
+    match diff(&format!("{:?}", actual), &format!("{:?}", expected)) {
+        Some(rendered) => format!("{}\n\tDiff:\n{}", message, rendered),
+        None => message,
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::equal;
+    use super::{anything, equal};
     use crate::Matcher;
 
+    #[test]
+    fn anything_always_matches() {
+        assert!(anything().match_value(&1));
+        assert!(anything().match_value(&"foo"));
+    }
+
     #[test]
     fn should_match_if_actual_equals_expected() {
         assert!(equal("foo").match_value(&"foo"))
@@ -65,13 +140,24 @@ mod tests {
 
     #[test]
     fn failure_messages() {
-        assert_eq!(
-            equal("foo").failure_message(&"bar"),
-            String::from("\tExpected:\n\t\t\"bar\"\n\tto equal:\n\t\t\"foo\"")
-        );
+        let message = equal("foo").failure_message(&"bar");
+        assert!(message.starts_with("\tExpected:\n\t\t\"bar\"\n\tto equal:\n\t\t\"foo\"\n\tDiff:\n"));
+        assert!(message.contains("\t  - b"));
+        assert!(message.contains("\t  + f"));
+
         assert_eq!(
             equal("foo").negated_failure_message(&"foo"),
             String::from("\tExpected:\n\t\t\"foo\"\n\tnot to equal:\n\t\t\"foo\"")
         );
     }
+
+    #[test]
+    fn failure_message_falls_back_to_plain_values_when_the_diff_is_not_useful() {
+        let huge = "x".repeat(1000);
+        let message = equal(huge.clone()).failure_message(&"y");
+        assert_eq!(
+            message,
+            format!("\tExpected:\n\t\t\"y\"\n\tto equal:\n\t\t{:?}", huge)
+        );
+    }
 }
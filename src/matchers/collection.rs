@@ -96,6 +96,18 @@ impl<T, V: Collection<T> + std::fmt::Debug> Matcher<V> for BeEmptyMatcher<T> {
 pub trait Collection<T> {
     fn contains_element(&self, element: &T) -> bool;
     fn empty(&self) -> bool;
+    fn elements(&self) -> Vec<&T>;
+}
+
+/// A [`Collection`] whose iteration order is well defined, unlike
+/// [`HashSet`](std::collections::HashSet)'s or [`BTreeSet`](std::collections::BTreeSet)'s
+/// (which iterates in sorted order regardless of insertion order). Required
+/// by matchers that care about the order of elements, such as [`be_sorted`].
+pub trait Sequence<T>: Collection<T> {
+    /// Returns the elements of the sequence, in iteration order.
+    fn sequence(&self) -> Vec<&T> {
+        self.elements()
+    }
 }
 
 macro_rules! array {
@@ -110,7 +122,14 @@ macro_rules! array {
                 fn empty(&self) -> bool {
                     self.is_empty()
                 }
+
+                fn elements(&self) -> Vec<&T> {
+                    self.iter().collect()
+                }
             }
+
+            #[doc(hidden)]
+            impl<T: std::cmp::PartialEq> Sequence<T> for [T; $N] {}
         )+
     }
 }
@@ -139,8 +158,14 @@ impl<T: std::cmp::PartialEq> Collection<T> for std::vec::Vec<T> {
     fn empty(&self) -> bool {
         self.is_empty()
     }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
 }
 
+impl<T: std::cmp::PartialEq> Sequence<T> for std::vec::Vec<T> {}
+
 impl<T: std::cmp::PartialEq> Collection<T> for std::collections::VecDeque<T> {
     fn contains_element(&self, element: &T) -> bool {
         self.contains(element)
@@ -149,8 +174,14 @@ impl<T: std::cmp::PartialEq> Collection<T> for std::collections::VecDeque<T> {
     fn empty(&self) -> bool {
         self.is_empty()
     }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
 }
 
+impl<T: std::cmp::PartialEq> Sequence<T> for std::collections::VecDeque<T> {}
+
 impl<T: std::cmp::PartialEq> Collection<T> for std::collections::LinkedList<T> {
     fn contains_element(&self, element: &T) -> bool {
         self.contains(element)
@@ -159,8 +190,14 @@ impl<T: std::cmp::PartialEq> Collection<T> for std::collections::LinkedList<T> {
     fn empty(&self) -> bool {
         self.is_empty()
     }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
 }
 
+impl<T: std::cmp::PartialEq> Sequence<T> for std::collections::LinkedList<T> {}
+
 impl<T: std::cmp::Eq + std::hash::Hash> Collection<T> for std::collections::HashSet<T> {
     fn contains_element(&self, element: &T) -> bool {
         self.contains(element)
@@ -169,6 +206,10 @@ impl<T: std::cmp::Eq + std::hash::Hash> Collection<T> for std::collections::Hash
     fn empty(&self) -> bool {
         self.is_empty()
     }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
 }
 
 impl<T: std::cmp::Ord> Collection<T> for std::collections::BTreeSet<T> {
@@ -179,11 +220,307 @@ impl<T: std::cmp::Ord> Collection<T> for std::collections::BTreeSet<T> {
     fn empty(&self) -> bool {
         self.is_empty()
     }
+
+    fn elements(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+}
+
+/// Matches if `actual` contains exactly the elements of `expected`, in any
+/// order, with duplicates accounted for.
+///
+/// Unlike [`contain`], this does not stop at the first match: `[1, 1, 2]`
+/// does not match `[1, 2, 2]`, since the second `1` has nothing left to pair
+/// with.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::contain_exactly_in_any_order};
+/// expect(&vec![3, 1, 2]).to(contain_exactly_in_any_order(vec![1, 2, 3]));
+/// expect(&vec![1, 1, 2]).not_to(contain_exactly_in_any_order(vec![1, 2, 2]));
+/// ```
+pub fn contain_exactly_in_any_order<T>(expected: Vec<T>) -> ContainExactlyInAnyOrderMatcher<T> {
+    ContainExactlyInAnyOrderMatcher { expected }
+}
+
+pub struct ContainExactlyInAnyOrderMatcher<T> {
+    expected: Vec<T>,
+}
+
+impl<T: std::cmp::PartialEq + std::fmt::Debug, V: Collection<T> + std::fmt::Debug> Matcher<V>
+    for ContainExactlyInAnyOrderMatcher<T>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        let actual = collection.elements();
+        if actual.len() != self.expected.len() {
+            return false;
+        }
+        let (_, match_right) = bipartite_match(&actual, &self.expected);
+        match_right.iter().all(Option::is_some)
+    }
+
+    fn failure_message(&self, collection: &V) -> String {
+        let actual = collection.elements();
+        let (match_left, match_right) = bipartite_match(&actual, &self.expected);
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tto contain exactly (in any order):\n\t\t{:?}\n\t{}",
+            collection,
+            self.expected,
+            describe_mismatch(&actual, &self.expected, &match_left, &match_right)
+        )
+    }
+
+    fn negated_failure_message(&self, collection: &V) -> String {
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tnot to contain exactly (in any order):\n\t\t{:?}",
+            collection, self.expected
+        )
+    }
+}
+
+/// Matches if `actual` contains at least the elements of `expected`, in any
+/// order, with duplicates accounted for. `actual` may also contain further
+/// elements not present in `expected`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::contain_all};
+/// expect(&vec![3, 1, 2, 2]).to(contain_all(vec![1, 2]));
+/// ```
+pub fn contain_all<T>(expected: Vec<T>) -> ContainAllMatcher<T> {
+    ContainAllMatcher { expected }
+}
+
+pub struct ContainAllMatcher<T> {
+    expected: Vec<T>,
+}
+
+impl<T: std::cmp::PartialEq + std::fmt::Debug, V: Collection<T> + std::fmt::Debug> Matcher<V>
+    for ContainAllMatcher<T>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        let actual = collection.elements();
+        let (_, match_right) = bipartite_match(&actual, &self.expected);
+        match_right.iter().all(Option::is_some)
+    }
+
+    fn failure_message(&self, collection: &V) -> String {
+        let actual = collection.elements();
+        let (_, match_right) = bipartite_match(&actual, &self.expected);
+        let unmatched: Vec<&T> = self
+            .expected
+            .iter()
+            .zip(match_right.iter())
+            .filter(|(_, m)| m.is_none())
+            .map(|(item, _)| item)
+            .collect();
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tto contain all of:\n\t\t{:?}\n\tMissing:\n\t\t{:?}",
+            collection, self.expected, unmatched
+        )
+    }
+
+    fn negated_failure_message(&self, collection: &V) -> String {
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tnot to contain all of:\n\t\t{:?}",
+            collection, self.expected
+        )
+    }
+}
+
+/// Runs Kuhn's algorithm for maximum bipartite matching between `actual`
+/// (left vertices) and `expected` (right vertices), with an edge wherever
+/// `actual[i] == expected[j]`.
+///
+/// Returns `(match_left, match_right)`, where `match_left[i]` is the
+/// expected index matched to `actual[i]` (if any) and `match_right[j]` is
+/// the actual index matched to `expected[j]` (if any).
+fn bipartite_match<T: std::cmp::PartialEq>(
+    actual: &[&T],
+    expected: &[T],
+) -> (Vec<Option<usize>>, Vec<Option<usize>>) {
+    let mut match_left: Vec<Option<usize>> = vec![None; actual.len()];
+    let mut match_right: Vec<Option<usize>> = vec![None; expected.len()];
+
+    for j in 0..expected.len() {
+        let mut visited = vec![false; actual.len()];
+        try_augment(j, actual, expected, &mut visited, &mut match_left, &mut match_right);
+    }
+
+    (match_left, match_right)
+}
+
+/// Attempts to find an augmenting path starting from expected-vertex `j`,
+/// flipping matched edges along the path when one is found.
+fn try_augment<T: std::cmp::PartialEq>(
+    j: usize,
+    actual: &[&T],
+    expected: &[T],
+    visited: &mut [bool],
+    match_left: &mut [Option<usize>],
+    match_right: &mut [Option<usize>],
+) -> bool {
+    for i in 0..actual.len() {
+        if visited[i] || actual[i] != &expected[j] {
+            continue;
+        }
+        visited[i] = true;
+
+        let can_use = match match_left[i] {
+            None => true,
+            Some(other_j) => try_augment(other_j, actual, expected, visited, match_left, match_right),
+        };
+
+        if can_use {
+            match_left[i] = Some(j);
+            match_right[j] = Some(i);
+            return true;
+        }
+    }
+    false
+}
+
+/// Describes an unsuccessful [`contain_exactly_in_any_order`] match: which
+/// expected items had no actual element to pair with, and which actual
+/// elements were left over once expected items were satisfied.
+fn describe_mismatch<T: std::fmt::Debug>(
+    actual: &[&T],
+    expected: &[T],
+    match_left: &[Option<usize>],
+    match_right: &[Option<usize>],
+) -> String {
+    let unmatched_expected: Vec<&T> = expected
+        .iter()
+        .zip(match_right.iter())
+        .filter(|(_, m)| m.is_none())
+        .map(|(item, _)| item)
+        .collect();
+    let leftover_actual: Vec<&&T> = actual
+        .iter()
+        .zip(match_left.iter())
+        .filter(|(_, m)| m.is_none())
+        .map(|(item, _)| item)
+        .collect();
+
+    format!(
+        "Unmatched expected:\n\t\t{:?}\n\tLeftover actual:\n\t\t{:?}",
+        unmatched_expected, leftover_actual
+    )
+}
+
+/// Matches if `actual`'s elements are in non-decreasing order.
+///
+/// Supports [arrays] of up to 256 elements, [`Vec`]s, [`VecDeque`]s and
+/// [`LinkedList`]s. `HashSet` and `BTreeSet` are not supported, since the
+/// former has no meaningful order and the latter is always sorted.
+///
+/// Adjacent elements that can't be compared (e.g. `f64::NAN`) are treated as
+/// out of order rather than panicking, so a `NaN` anywhere in the collection
+/// simply fails the match.
+///
+/// [array]: https://doc.rust-lang.org/std/primitive.array.html
+/// [`Vec`]: https://doc.rust-lang.org/std/vec/struct.Vec.html
+/// [`VecDeque`]: https://doc.rust-lang.org/std/collections/struct.VecDeque.html
+/// [`LinkedList`]: https://doc.rust-lang.org/std/collections/struct.LinkedList.html
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_sorted};
+/// expect(&vec![1, 2, 2, 3]).to(be_sorted());
+/// expect(&vec![3, 1, 2]).not_to(be_sorted());
+/// ```
+pub fn be_sorted<T: std::cmp::PartialOrd>(
+) -> SortedMatcher<T, fn(&T, &T) -> std::cmp::Ordering> {
+    SortedMatcher {
+        compare: |a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Greater),
+        phantom: PhantomData,
+    }
+}
+
+/// Matches if `actual`'s elements are in non-increasing order.
+///
+/// Adjacent elements that can't be compared (e.g. `f64::NAN`) are treated as
+/// out of order rather than panicking, so a `NaN` anywhere in the collection
+/// simply fails the match.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_sorted_descending};
+/// expect(&vec![3, 2, 2, 1]).to(be_sorted_descending());
+/// ```
+pub fn be_sorted_descending<T: std::cmp::PartialOrd>(
+) -> SortedMatcher<T, fn(&T, &T) -> std::cmp::Ordering> {
+    SortedMatcher {
+        compare: |a, b| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Greater),
+        phantom: PhantomData,
+    }
+}
+
+/// Matches if `actual`'s elements are ordered according to `compare`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::collection::be_sorted_by};
+/// expect(&vec!["ccc", "bb", "a"]).to(be_sorted_by(|a: &&str, b: &&str| b.len().cmp(&a.len())));
+/// ```
+pub fn be_sorted_by<T, F: Fn(&T, &T) -> std::cmp::Ordering>(compare: F) -> SortedMatcher<T, F> {
+    SortedMatcher {
+        compare,
+        phantom: PhantomData,
+    }
+}
+
+pub struct SortedMatcher<T, F> {
+    compare: F,
+    phantom: PhantomData<T>,
+}
+
+impl<T: std::fmt::Debug, F: Fn(&T, &T) -> std::cmp::Ordering, V: Sequence<T> + std::fmt::Debug>
+    Matcher<V> for SortedMatcher<T, F>
+{
+    fn match_value(&self, collection: &V) -> bool {
+        first_disorder(&collection.sequence(), &self.compare).is_none()
+    }
+
+    fn failure_message(&self, collection: &V) -> String {
+        match first_disorder(&collection.sequence(), &self.compare) {
+            Some((index, previous, current)) => format!(
+                "\tExpected:\n\t\t{:?}\n\tto be sorted, but the element at index {} ({:?}) comes before the element at index {} ({:?}), which is out of order",
+                collection, index - 1, previous, index, current
+            ),
+            None => format!("\tExpected:\n\t\t{:?}\n\tto be sorted", collection),
+        }
+    }
+
+    fn negated_failure_message(&self, collection: &V) -> String {
+        format!("\tExpected:\n\t\t{:?}\n\tnot to be sorted", collection)
+    }
+}
+
+/// Finds the first adjacent pair in `elements` that violates `compare`,
+/// returning the index of the second element of the pair along with both
+/// elements.
+fn first_disorder<'a, T, F: Fn(&T, &T) -> std::cmp::Ordering>(
+    elements: &[&'a T],
+    compare: &F,
+) -> Option<(usize, &'a T, &'a T)> {
+    elements
+        .windows(2)
+        .position(|pair| compare(pair[0], pair[1]) == std::cmp::Ordering::Greater)
+        .map(|index| (index + 1, elements[index], elements[index + 1]))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{be_empty, contain, Collection};
+    use super::{
+        be_empty, be_sorted, be_sorted_by, be_sorted_descending, contain, contain_all,
+        contain_exactly_in_any_order, Collection,
+    };
     use crate::Matcher;
 
     #[test]
@@ -325,4 +662,104 @@ mod tests {
 
         assert!(numbers.contains_element(&2))
     }
+
+    #[test]
+    fn contain_exactly_in_any_order_matches_regardless_of_order() {
+        assert!(contain_exactly_in_any_order(vec![1, 2, 3]).match_value(&vec![3, 1, 2]))
+    }
+
+    #[test]
+    fn contain_exactly_in_any_order_accounts_for_duplicates() {
+        assert!(!contain_exactly_in_any_order(vec![1, 2, 2]).match_value(&vec![1, 1, 2]));
+        assert!(contain_exactly_in_any_order(vec![1, 1, 2]).match_value(&vec![1, 1, 2]));
+    }
+
+    #[test]
+    fn contain_exactly_in_any_order_does_not_match_different_cardinalities() {
+        assert!(!contain_exactly_in_any_order(vec![1, 2]).match_value(&vec![1, 2, 3]));
+        assert!(!contain_exactly_in_any_order(vec![1, 2, 3]).match_value(&vec![1, 2]));
+    }
+
+    #[test]
+    fn contain_exactly_in_any_order_failure_message_reports_unmatched_items() {
+        let message = contain_exactly_in_any_order(vec![1, 2, 2]).failure_message(&vec![1, 1, 2]);
+        assert!(message.contains("Unmatched expected:\n\t\t[2]"));
+        assert!(message.contains("Leftover actual:\n\t\t[1]"));
+    }
+
+    #[test]
+    fn contain_all_matches_a_subset_regardless_of_order() {
+        assert!(contain_all(vec![1, 2]).match_value(&vec![3, 1, 2, 2]))
+    }
+
+    #[test]
+    fn contain_all_does_not_match_if_a_duplicate_is_missing() {
+        assert!(!contain_all(vec![1, 1]).match_value(&vec![1, 2, 3]))
+    }
+
+    #[test]
+    fn contain_all_failure_message_reports_missing_items() {
+        let message = contain_all(vec![1, 4]).failure_message(&vec![1, 2, 3]);
+        assert!(message.contains("Missing:\n\t\t[4]"));
+    }
+
+    #[test]
+    fn be_sorted_matches_non_decreasing_collections() {
+        assert!(be_sorted().match_value(&vec![1, 2, 2, 3]));
+        assert!(!be_sorted().match_value(&vec![3, 1, 2]));
+    }
+
+    #[test]
+    fn be_sorted_matches_empty_and_single_element_collections() {
+        assert!(be_sorted().match_value(&std::vec::Vec::<i32>::new()));
+        assert!(be_sorted().match_value(&vec![1]));
+    }
+
+    #[test]
+    fn be_sorted_failure_message_names_the_offending_index_and_elements() {
+        let message = be_sorted().failure_message(&vec![1, 3, 2]);
+        assert_eq!(
+            message,
+            "\tExpected:\n\t\t[1, 3, 2]\n\tto be sorted, but the element at index 1 (3) comes before the element at index 2 (2), which is out of order"
+        );
+    }
+
+    #[test]
+    fn be_sorted_negated_failure_message() {
+        assert_eq!(
+            be_sorted().negated_failure_message(&vec![1, 2, 3]),
+            String::from("\tExpected:\n\t\t[1, 2, 3]\n\tnot to be sorted")
+        );
+    }
+
+    #[test]
+    fn be_sorted_descending_matches_non_increasing_collections() {
+        assert!(be_sorted_descending().match_value(&vec![3, 2, 2, 1]));
+        assert!(!be_sorted_descending().match_value(&vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn be_sorted_by_matches_using_the_given_comparator() {
+        assert!(be_sorted_by(|a: &i32, b: &i32| b.cmp(a)).match_value(&vec![3, 2, 1]));
+        assert!(!be_sorted_by(|a: &i32, b: &i32| b.cmp(a)).match_value(&vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn be_sorted_supports_vecdeques_and_linkedlists() {
+        let mut deque = std::collections::VecDeque::new();
+        deque.push_back(1);
+        deque.push_back(2);
+        assert!(be_sorted().match_value(&deque));
+
+        let mut list = std::collections::LinkedList::new();
+        list.push_back(2);
+        list.push_back(1);
+        assert!(!be_sorted().match_value(&list));
+    }
+
+    #[test]
+    fn be_sorted_does_not_panic_on_incomparable_elements_like_nan() {
+        assert!(!be_sorted().match_value(&vec![1.0, f64::NAN, 2.0]));
+        assert!(!be_sorted_descending().match_value(&vec![2.0, f64::NAN, 1.0]));
+    }
 }
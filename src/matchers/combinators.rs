@@ -0,0 +1,243 @@
+//! Wrapper matchers that compose other matchers together: [`AndMatcher`],
+//! [`OrMatcher`] (built via [`Matcher::and`](crate::Matcher::and) and
+//! [`Matcher::or`](crate::Matcher::or)) and [`NotMatcher`] (built via [`not`]).
+
+use super::indent;
+use crate::Matcher;
+use std::marker::PhantomData;
+
+/// Matches only when both wrapped matchers match. See [`Matcher::and`](crate::Matcher::and).
+///
+/// Carries a `PhantomData<T>` so the actual-value type stays visible in this
+/// type's signature; without it, type inference can't work back from
+/// `expect(...).to(...)` to pick which of a matcher's (possibly many)
+/// `Matcher<T>` implementations is meant at the `.and(...)` call site.
+pub struct AndMatcher<T, A, B> {
+    left: A,
+    right: B,
+    phantom: PhantomData<T>,
+}
+
+impl<T, A, B> AndMatcher<T, A, B> {
+    pub(crate) fn new(left: A, right: B) -> Self {
+        AndMatcher {
+            left,
+            right,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Matcher<T>, B: Matcher<T>> Matcher<T> for AndMatcher<T, A, B> {
+    fn match_value(&self, actual: &T) -> bool {
+        self.left.match_value(actual) && self.right.match_value(actual)
+    }
+
+    fn failure_message(&self, actual: &T) -> String {
+        let mut lines = vec![String::from("\texpected all of:")];
+        if !self.left.match_value(actual) {
+            lines.push(indent(&self.left.failure_message(actual)));
+        }
+        if !self.right.match_value(actual) {
+            lines.push(indent(&self.right.failure_message(actual)));
+        }
+        lines.join("\n")
+    }
+
+    fn negated_failure_message(&self, actual: &T) -> String {
+        format!(
+            "\texpected not all of:\n{}\n{}",
+            indent(&self.left.failure_message(actual)),
+            indent(&self.right.failure_message(actual))
+        )
+    }
+}
+
+/// Matches when either wrapped matcher matches. See [`Matcher::or`](crate::Matcher::or).
+///
+/// Carries a `PhantomData<T>` for the same inference reason as [`AndMatcher`].
+pub struct OrMatcher<T, A, B> {
+    left: A,
+    right: B,
+    phantom: PhantomData<T>,
+}
+
+impl<T, A, B> OrMatcher<T, A, B> {
+    pub(crate) fn new(left: A, right: B) -> Self {
+        OrMatcher {
+            left,
+            right,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Matcher<T>, B: Matcher<T>> Matcher<T> for OrMatcher<T, A, B> {
+    fn match_value(&self, actual: &T) -> bool {
+        self.left.match_value(actual) || self.right.match_value(actual)
+    }
+
+    fn failure_message(&self, actual: &T) -> String {
+        format!(
+            "\texpected any of:\n{}\n{}",
+            indent(&self.left.failure_message(actual)),
+            indent(&self.right.failure_message(actual))
+        )
+    }
+
+    fn negated_failure_message(&self, actual: &T) -> String {
+        let mut lines = vec![String::from("\texpected none of:")];
+        if self.left.match_value(actual) {
+            lines.push(indent(&self.left.negated_failure_message(actual)));
+        }
+        if self.right.match_value(actual) {
+            lines.push(indent(&self.right.negated_failure_message(actual)));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Wraps `matcher`, inverting whether it matches and swapping its failure
+/// and negated-failure messages.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::equal, matchers::combinators::not};
+/// expect(&1).to(not(equal(2)));
+/// ```
+pub fn not<T, M: Matcher<T>>(matcher: M) -> NotMatcher<T, M> {
+    NotMatcher {
+        inner: matcher,
+        phantom: PhantomData,
+    }
+}
+
+/// Inverts a wrapped matcher. See [`not`].
+///
+/// Carries a `PhantomData<T>` for the same inference reason as [`AndMatcher`].
+pub struct NotMatcher<T, M> {
+    inner: M,
+    phantom: PhantomData<T>,
+}
+
+impl<T, M: Matcher<T>> Matcher<T> for NotMatcher<T, M> {
+    fn match_value(&self, actual: &T) -> bool {
+        !self.inner.match_value(actual)
+    }
+
+    fn failure_message(&self, actual: &T) -> String {
+        self.inner.negated_failure_message(actual)
+    }
+
+    fn negated_failure_message(&self, actual: &T) -> String {
+        self.inner.failure_message(actual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::not;
+    use crate::matchers::equal;
+    use crate::Matcher;
+    use std::cell::Cell;
+
+    struct CountingMatcher<'a> {
+        matches: bool,
+        calls: &'a Cell<usize>,
+    }
+
+    impl<'a, T> Matcher<T> for CountingMatcher<'a> {
+        fn match_value(&self, _actual: &T) -> bool {
+            self.calls.set(self.calls.get() + 1);
+            self.matches
+        }
+
+        fn failure_message(&self, _actual: &T) -> String {
+            String::from("counting matcher failed")
+        }
+
+        fn negated_failure_message(&self, _actual: &T) -> String {
+            String::from("counting matcher unexpectedly matched")
+        }
+    }
+
+    #[test]
+    fn and_matches_only_if_both_sides_match() {
+        assert!(equal(1).and(equal(1)).match_value(&1));
+        assert!(!equal(1).and(equal(2)).match_value(&1));
+        assert!(!equal(2).and(equal(1)).match_value(&1));
+    }
+
+    #[test]
+    fn and_short_circuits_on_the_first_failing_matcher() {
+        let calls = Cell::new(0);
+        let left = CountingMatcher {
+            matches: false,
+            calls: &calls,
+        };
+        let right = CountingMatcher {
+            matches: true,
+            calls: &calls,
+        };
+
+        assert!(!left.and(right).match_value(&1));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn or_matches_if_either_side_matches() {
+        assert!(equal(1).or(equal(2)).match_value(&1));
+        assert!(equal(1).or(equal(2)).match_value(&2));
+        assert!(!equal(1).or(equal(2)).match_value(&3));
+    }
+
+    #[test]
+    fn or_short_circuits_on_the_first_matching_matcher() {
+        let calls = Cell::new(0);
+        let left = CountingMatcher {
+            matches: true,
+            calls: &calls,
+        };
+        let right = CountingMatcher {
+            matches: true,
+            calls: &calls,
+        };
+
+        assert!(left.or(right).match_value(&1));
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn not_inverts_match_value() {
+        assert!(not(equal(2)).match_value(&1));
+        assert!(!not(equal(1)).match_value(&1));
+    }
+
+    #[test]
+    fn not_swaps_the_failure_messages() {
+        let matcher = equal(1);
+        assert_eq!(
+            not(equal(1)).failure_message(&1),
+            matcher.negated_failure_message(&1)
+        );
+        assert_eq!(
+            not(equal(1)).negated_failure_message(&2),
+            matcher.failure_message(&2)
+        );
+    }
+
+    #[test]
+    fn and_failure_message_combines_failing_sub_messages() {
+        let message = equal(1).and(equal(2)).failure_message(&1);
+        assert!(message.starts_with("\texpected all of:"));
+        assert!(message.contains("to equal"));
+    }
+
+    #[test]
+    fn or_failure_message_combines_both_sub_messages() {
+        let message = equal(1).or(equal(2)).failure_message(&3);
+        assert!(message.starts_with("\texpected any of:"));
+        assert!(message.matches("to equal").count() == 2);
+    }
+}
@@ -0,0 +1,185 @@
+//! Edit-distance based diffing used to highlight what changed between two
+//! string-like values in failure messages, instead of printing both values
+//! in full.
+
+/// How far the band around the DP diagonal is allowed to extend before we
+/// give up and let the caller fall back to the plain message.
+const BAND: usize = 64;
+
+/// A sentinel cost used for cells outside the band (or otherwise
+/// unreachable). Kept well below `usize::MAX` so it can be added to without
+/// overflowing.
+const UNREACHABLE: usize = usize::MAX / 4;
+
+enum Edit {
+    Keep(String),
+    Delete(String),
+    Insert(String),
+    Substitute(String, String),
+}
+
+/// Renders a unified-style diff between `actual` and `expected`.
+///
+/// Diffs line-by-line when either value spans multiple lines, and
+/// character-by-character otherwise. Returns `None` when the two values are
+/// too different in length for a banded edit distance to reach both corners
+/// of the table, in which case the caller should fall back to printing the
+/// plain values.
+pub(crate) fn diff(actual: &str, expected: &str) -> Option<String> {
+    let edits = if actual.contains('\n') || expected.contains('\n') {
+        let a: Vec<String> = actual.lines().map(String::from).collect();
+        let e: Vec<String> = expected.lines().map(String::from).collect();
+        edit_script(&a, &e)?
+    } else {
+        let a: Vec<String> = actual.chars().map(String::from).collect();
+        let e: Vec<String> = expected.chars().map(String::from).collect();
+        edit_script(&a, &e)?
+    };
+
+    Some(
+        edits
+            .into_iter()
+            .map(|edit| match edit {
+                Edit::Keep(item) => format!("\t    {}", item),
+                Edit::Delete(item) => format!("\t  - {}", item),
+                Edit::Insert(item) => format!("\t  + {}", item),
+                Edit::Substitute(from, to) => format!("\t  - {}\n\t  + {}", from, to),
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// The width of a banded DP row: one cell per offset `j - i` in
+/// `-BAND..=BAND`.
+const BAND_WIDTH: usize = 2 * BAND + 1;
+
+/// Fills a banded Levenshtein DP table for `a` and `b` and backtraces it
+/// into a reversed-then-corrected edit script.
+///
+/// `dp[i][j]` holds the minimum number of edits needed to turn the first `i`
+/// items of `a` into the first `j` items of `b`. Only cells with
+/// `|i - j| <= BAND` are ever computed, and only those cells are stored: each
+/// row keeps a [`BAND_WIDTH`]-wide slice indexed by `j - i + BAND`, so memory
+/// and time stay `O(n * BAND)` rather than `O(n * m)` even when `a` and `b`
+/// are both huge and merely equal in length.
+fn edit_script(a: &[String], b: &[String]) -> Option<Vec<Edit>> {
+    let n = a.len();
+    let m = b.len();
+
+    if (n as isize - m as isize).unsigned_abs() > BAND {
+        return None;
+    }
+
+    // j is restricted to i - BAND ..= i + BAND, so the offset j - i + BAND
+    // always lands in 0..BAND_WIDTH.
+    let offset = |i: usize, j: usize| (j as isize - i as isize + BAND as isize) as usize;
+    let cell = |dp: &[Vec<usize>], i: usize, j: usize| -> usize {
+        if (j as isize - i as isize).unsigned_abs() > BAND {
+            UNREACHABLE
+        } else {
+            dp[i][offset(i, j)]
+        }
+    };
+
+    let mut dp = vec![vec![UNREACHABLE; BAND_WIDTH]; n + 1];
+    dp[0][offset(0, 0)] = 0;
+
+    for i in 0..=n {
+        let lo = i.saturating_sub(BAND);
+        let hi = (i + BAND).min(m);
+        for j in lo..=hi {
+            if i == 0 && j == 0 {
+                continue;
+            }
+
+            let mut best = UNREACHABLE;
+            if i > 0 && j > 0 {
+                let substitution = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+                best = best.min(cell(&dp, i - 1, j - 1) + substitution);
+            }
+            if i > 0 {
+                best = best.min(cell(&dp, i - 1, j) + 1);
+            }
+            if j > 0 {
+                best = best.min(cell(&dp, i, j - 1) + 1);
+            }
+            dp[i][offset(i, j)] = best;
+        }
+    }
+
+    if cell(&dp, n, m) >= UNREACHABLE {
+        return None;
+    }
+
+    let mut edits = Vec::new();
+    let (mut i, mut j) = (n, m);
+    while i > 0 || j > 0 {
+        if i > 0 && j > 0 && a[i - 1] == b[j - 1] && cell(&dp, i, j) == cell(&dp, i - 1, j - 1) {
+            edits.push(Edit::Keep(a[i - 1].clone()));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && j > 0 && cell(&dp, i, j) == cell(&dp, i - 1, j - 1) + 1 {
+            edits.push(Edit::Substitute(a[i - 1].clone(), b[j - 1].clone()));
+            i -= 1;
+            j -= 1;
+        } else if i > 0 && cell(&dp, i, j) == cell(&dp, i - 1, j) + 1 {
+            edits.push(Edit::Delete(a[i - 1].clone()));
+            i -= 1;
+        } else {
+            edits.push(Edit::Insert(b[j - 1].clone()));
+            j -= 1;
+        }
+    }
+    edits.reverse();
+
+    Some(edits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff;
+
+    #[test]
+    fn renders_only_keeps_for_identical_values() {
+        let result = diff("same", "same").unwrap();
+        assert!(!result.contains('-') && !result.contains('+'));
+    }
+
+    #[test]
+    fn highlights_a_single_character_substitution() {
+        let result = diff("cot", "cat").unwrap();
+        assert!(result.contains("\t  - o"));
+        assert!(result.contains("\t  + a"));
+        assert!(result.contains("\t    c"));
+        assert!(result.contains("\t    t"));
+    }
+
+    #[test]
+    fn diffs_multiline_values_by_line() {
+        let result = diff("one\ntwo\nthree", "one\ntoo\nthree").unwrap();
+        assert!(result.contains("\t  - two"));
+        assert!(result.contains("\t  + too"));
+        assert!(result.contains("\t    one"));
+        assert!(result.contains("\t    three"));
+    }
+
+    #[test]
+    fn falls_back_to_none_when_lengths_are_too_far_apart() {
+        let actual = "x".repeat(1000);
+        let expected = "x".to_string();
+        assert_eq!(diff(&actual, &expected), None);
+    }
+
+    #[test]
+    fn handles_large_equal_length_inputs_within_the_band() {
+        // Equal-length inputs always pass the `|n - m| <= BAND` check, so the
+        // banded table has to stay narrow rather than dense even here.
+        let actual = "x".repeat(20_000);
+        let mut expected = actual.clone();
+        expected.replace_range(10_000..10_001, "y");
+        let result = diff(&actual, &expected).unwrap();
+        assert!(result.contains("\t  - x"));
+        assert!(result.contains("\t  + y"));
+    }
+}
@@ -0,0 +1,192 @@
+//! Matchers for numeric values.
+
+use crate::Matcher;
+
+/// Matches if `actual` is close enough to `expected` for [`be_close_to`],
+/// either within an absolute tolerance or, once
+/// [`or_within_ulps`](CloseToMatcher::or_within_ulps) is set, within a
+/// number of representable floats (ULPs) of it.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::numeric::be_close_to};
+/// expect(&0.1_f64).to(be_close_to(0.1 + f64::EPSILON, 1e-9));
+/// expect(&1.0_f32).not_to(be_close_to(2.0, 0.1));
+/// ```
+pub fn be_close_to<T: Float>(expected: T, tolerance: T) -> CloseToMatcher<T> {
+    CloseToMatcher {
+        expected,
+        tolerance,
+        max_ulps: None,
+    }
+}
+
+pub struct CloseToMatcher<T> {
+    expected: T,
+    tolerance: T,
+    max_ulps: Option<u64>,
+}
+
+impl<T: Float> CloseToMatcher<T> {
+    /// Also accept values within `max_ulps` representable floats of
+    /// `expected`, for comparisons whose acceptable error should scale with
+    /// magnitude rather than use a fixed absolute tolerance.
+    pub fn or_within_ulps(mut self, max_ulps: u64) -> Self {
+        self.max_ulps = Some(max_ulps);
+        self
+    }
+}
+
+impl<T: Float> Matcher<T> for CloseToMatcher<T> {
+    fn match_value(&self, actual: &T) -> bool {
+        let actual = *actual;
+
+        if actual == self.expected {
+            return true;
+        }
+        if actual.is_nan() || self.expected.is_nan() {
+            return false;
+        }
+        if actual.abs_diff(self.expected) <= self.tolerance {
+            return true;
+        }
+
+        match self.max_ulps {
+            Some(max_ulps) => actual.ulps_diff(self.expected) <= max_ulps,
+            None => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &T) -> String {
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tto be close to:\n\t\t{:?}\n\twithin tolerance:\n\t\t{:?}\n\tbut differed by:\n\t\t{:?}",
+            actual,
+            self.expected,
+            self.tolerance,
+            actual.abs_diff(self.expected)
+        )
+    }
+
+    fn negated_failure_message(&self, actual: &T) -> String {
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tnot to be close to:\n\t\t{:?}\n\twithin tolerance:\n\t\t{:?}",
+            actual, self.expected, self.tolerance
+        )
+    }
+}
+
+/// A floating-point type [`be_close_to`] can compare.
+///
+/// `ulps_diff` reinterprets the bits of each value as a signed integer,
+/// flipping the sign bit so the integer ordering matches float ordering,
+/// then returns the absolute difference of those integers.
+pub trait Float: Copy + PartialEq + PartialOrd + std::fmt::Debug {
+    fn abs_diff(self, other: Self) -> Self;
+    fn is_nan(self) -> bool;
+    fn ulps_diff(self, other: Self) -> u64;
+}
+
+impl Float for f32 {
+    fn abs_diff(self, other: Self) -> Self {
+        (self - other).abs()
+    }
+
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+    fn ulps_diff(self, other: Self) -> u64 {
+        (ordered_i32(self) as i64 - ordered_i32(other) as i64).unsigned_abs()
+    }
+}
+
+impl Float for f64 {
+    fn abs_diff(self, other: Self) -> Self {
+        (self - other).abs()
+    }
+
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
+    fn ulps_diff(self, other: Self) -> u64 {
+        (ordered_i64(self) as i128 - ordered_i64(other) as i128).unsigned_abs() as u64
+    }
+}
+
+/// Maps an `f32`'s bit pattern to a signed integer whose ordering matches
+/// the float's, by flipping the sign bit (rather than just reinterpreting
+/// the bits, which would sort negative floats backwards).
+fn ordered_i32(value: f32) -> i32 {
+    let bits = value.to_bits() as i32;
+    if bits < 0 {
+        i32::MIN.wrapping_sub(bits)
+    } else {
+        bits
+    }
+}
+
+/// The `f64` counterpart of [`ordered_i32`].
+fn ordered_i64(value: f64) -> i64 {
+    let bits = value.to_bits() as i64;
+    if bits < 0 {
+        i64::MIN.wrapping_sub(bits)
+    } else {
+        bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::be_close_to;
+    use crate::Matcher;
+
+    #[test]
+    fn matches_exactly_equal_values() {
+        assert!(be_close_to(1.0_f64, 0.0).match_value(&1.0));
+    }
+
+    #[test]
+    fn matches_within_the_absolute_tolerance() {
+        assert!(be_close_to(1.0_f64, 0.01).match_value(&1.005));
+        assert!(!be_close_to(1.0_f64, 0.01).match_value(&1.02));
+    }
+
+    #[test]
+    fn nan_never_matches_even_itself() {
+        assert!(!be_close_to(f64::NAN, 1.0).match_value(&f64::NAN));
+        assert!(!be_close_to(1.0, 1.0).match_value(&f64::NAN));
+    }
+
+    #[test]
+    fn infinities_only_match_themselves() {
+        assert!(be_close_to(f64::INFINITY, 1.0).match_value(&f64::INFINITY));
+        assert!(!be_close_to(f64::INFINITY, 1.0).match_value(&f64::NEG_INFINITY));
+        assert!(!be_close_to(f64::INFINITY, 1.0).match_value(&1.0));
+    }
+
+    #[test]
+    fn or_within_ulps_matches_values_a_few_representable_floats_apart() {
+        let just_above_one = 1.0_f64 + f64::EPSILON;
+        assert!(!be_close_to(1.0_f64, 0.0).match_value(&just_above_one));
+        assert!(be_close_to(1.0_f64, 0.0)
+            .or_within_ulps(4)
+            .match_value(&just_above_one));
+    }
+
+    #[test]
+    fn or_within_ulps_does_not_match_values_too_far_apart() {
+        assert!(!be_close_to(1.0_f64, 0.0)
+            .or_within_ulps(4)
+            .match_value(&1.1));
+    }
+
+    #[test]
+    fn failure_message_reports_the_observed_difference() {
+        assert_eq!(
+            be_close_to(1.0_f64, 0.01).failure_message(&1.5),
+            "\tExpected:\n\t\t1.5\n\tto be close to:\n\t\t1.0\n\twithin tolerance:\n\t\t0.01\n\tbut differed by:\n\t\t0.5"
+        );
+    }
+}
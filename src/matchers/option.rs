@@ -0,0 +1,7 @@
+//! Matchers for `Option` values.
+
+mod be_none;
+mod be_some;
+
+pub use be_none::*;
+pub use be_some::*;
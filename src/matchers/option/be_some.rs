@@ -0,0 +1,99 @@
+use crate::matchers::indent;
+use crate::Matcher;
+
+use std::marker::PhantomData;
+
+/// Matches if `actual` is `Some`, and its wrapped value matches `inner`.
+///
+/// Use [`anything`](crate::matchers::anything) as `inner` to only check the
+/// variant, ignoring the wrapped value.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::{equal, anything}, matchers::option::be_some};
+/// expect(&Some(5)).to(be_some(equal(5)));
+/// expect(&Some("thing")).to(be_some(anything()));
+/// expect(&None::<i32>).not_to(be_some(anything()));
+/// ```
+pub fn be_some<T, M: Matcher<T>>(inner: M) -> SomeMatcher<T, M> {
+    SomeMatcher {
+        inner,
+        phantom: PhantomData,
+    }
+}
+
+pub struct SomeMatcher<T, M> {
+    inner: M,
+    phantom: PhantomData<T>,
+}
+
+impl<T: std::fmt::Debug, M: Matcher<T>> Matcher<Option<T>> for SomeMatcher<T, M> {
+    fn match_value(&self, actual: &Option<T>) -> bool {
+        match actual {
+            Some(value) => self.inner.match_value(value),
+            None => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &Option<T>) -> String {
+        match actual {
+            Some(value) => format!(
+                "\tExpected:\n\t\t{:?}\n\tto be Some, but the inner matcher failed:\n{}",
+                actual,
+                indent(&self.inner.failure_message(value))
+            ),
+            None => format!("\tExpected:\n\t\t{:?}\n\tto be Some", actual),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &Option<T>) -> String {
+        format!("\tExpected:\n\t\t{:?}\n\tnot to be Some", actual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::be_some;
+    use crate::expect;
+    use crate::matchers::{anything, equal};
+    use crate::Matcher;
+
+    #[test]
+    fn should_match_if_actual_is_some_and_the_inner_matcher_matches() {
+        assert!(be_some(equal("foo")).match_value(&Some("foo")))
+    }
+
+    #[test]
+    fn should_not_match_if_actual_is_some_but_the_inner_matcher_does_not_match() {
+        assert!(!be_some(equal("foo")).match_value(&Some("bar")))
+    }
+
+    #[test]
+    fn should_not_match_if_actual_is_none() {
+        assert!(!be_some(anything()).match_value(&None::<&str>))
+    }
+
+    #[test]
+    fn failure_messages() {
+        assert_eq!(
+            be_some(equal("foo")).failure_message(&None::<&str>),
+            String::from("\tExpected:\n\t\tNone\n\tto be Some")
+        );
+        assert!(be_some(equal("foo"))
+            .failure_message(&Some("bar"))
+            .starts_with(
+                "\tExpected:\n\t\tSome(\"bar\")\n\tto be Some, but the inner matcher failed:\n\t\tExpected:"
+            ));
+        assert_eq!(
+            be_some(anything()).negated_failure_message(&Some("foo")),
+            String::from("\tExpected:\n\t\tSome(\"foo\")\n\tnot to be Some")
+        );
+    }
+
+    #[test]
+    fn be_some_should_construct_a_some_matcher() {
+        expect(&Some("thing")).to(be_some(anything()));
+        expect(&Some(5)).to(be_some(equal(5)));
+    }
+}
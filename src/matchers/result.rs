@@ -0,0 +1,157 @@
+//! Matchers for `Result` values.
+
+use crate::matchers::indent;
+use crate::Matcher;
+
+use std::marker::PhantomData;
+
+/// Matches if `actual` is `Ok`, and its wrapped value matches `inner`.
+///
+/// Use [`anything`](crate::matchers::anything) as `inner` to only check the
+/// variant, ignoring the wrapped value.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::equal, matchers::result::be_ok};
+/// expect(&Ok::<i32, String>(5)).to(be_ok(equal(5)));
+/// ```
+pub fn be_ok<T, E, M: Matcher<T>>(inner: M) -> OkMatcher<T, E, M> {
+    OkMatcher {
+        inner,
+        phantom: PhantomData,
+    }
+}
+
+pub struct OkMatcher<T, E, M> {
+    inner: M,
+    phantom: PhantomData<(T, E)>,
+}
+
+impl<T: std::fmt::Debug, E: std::fmt::Debug, M: Matcher<T>> Matcher<Result<T, E>>
+    for OkMatcher<T, E, M>
+{
+    fn match_value(&self, actual: &Result<T, E>) -> bool {
+        match actual {
+            Ok(value) => self.inner.match_value(value),
+            Err(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &Result<T, E>) -> String {
+        match actual {
+            Ok(value) => format!(
+                "\tExpected:\n\t\t{:?}\n\tto be Ok, but the inner matcher failed:\n{}",
+                actual,
+                indent(&self.inner.failure_message(value))
+            ),
+            Err(_) => format!("\tExpected:\n\t\t{:?}\n\tto be Ok", actual),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &Result<T, E>) -> String {
+        format!("\tExpected:\n\t\t{:?}\n\tnot to be Ok", actual)
+    }
+}
+
+/// Matches if `actual` is `Err`, and its wrapped value matches `inner`.
+///
+/// Use [`anything`](crate::matchers::anything) as `inner` to only check the
+/// variant, ignoring the wrapped value.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::equal, matchers::result::be_err};
+/// expect(&Err::<i32, String>(String::from("oops"))).to(be_err(equal(String::from("oops"))));
+/// ```
+pub fn be_err<T, E, M: Matcher<E>>(inner: M) -> ErrMatcher<T, E, M> {
+    ErrMatcher {
+        inner,
+        phantom: PhantomData,
+    }
+}
+
+pub struct ErrMatcher<T, E, M> {
+    inner: M,
+    phantom: PhantomData<(T, E)>,
+}
+
+impl<T: std::fmt::Debug, E: std::fmt::Debug, M: Matcher<E>> Matcher<Result<T, E>>
+    for ErrMatcher<T, E, M>
+{
+    fn match_value(&self, actual: &Result<T, E>) -> bool {
+        match actual {
+            Err(value) => self.inner.match_value(value),
+            Ok(_) => false,
+        }
+    }
+
+    fn failure_message(&self, actual: &Result<T, E>) -> String {
+        match actual {
+            Err(value) => format!(
+                "\tExpected:\n\t\t{:?}\n\tto be Err, but the inner matcher failed:\n{}",
+                actual,
+                indent(&self.inner.failure_message(value))
+            ),
+            Ok(_) => format!("\tExpected:\n\t\t{:?}\n\tto be Err", actual),
+        }
+    }
+
+    fn negated_failure_message(&self, actual: &Result<T, E>) -> String {
+        format!("\tExpected:\n\t\t{:?}\n\tnot to be Err", actual)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{be_err, be_ok};
+    use crate::matchers::{anything, equal};
+    use crate::Matcher;
+
+    #[test]
+    fn be_ok_should_match_if_actual_is_ok_and_the_inner_matcher_matches() {
+        assert!(be_ok(equal(5)).match_value(&Ok::<i32, String>(5)))
+    }
+
+    #[test]
+    fn be_ok_should_not_match_if_actual_is_ok_but_the_inner_matcher_does_not_match() {
+        assert!(!be_ok(equal(5)).match_value(&Ok::<i32, String>(6)))
+    }
+
+    #[test]
+    fn be_ok_should_not_match_if_actual_is_err() {
+        assert!(!be_ok(anything()).match_value(&Err::<i32, String>(String::from("oops"))))
+    }
+
+    #[test]
+    fn be_err_should_match_if_actual_is_err_and_the_inner_matcher_matches() {
+        assert!(be_err(equal(String::from("oops")))
+            .match_value(&Err::<i32, String>(String::from("oops"))))
+    }
+
+    #[test]
+    fn be_err_should_not_match_if_actual_is_ok() {
+        assert!(!be_err(anything()).match_value(&Ok::<i32, String>(5)))
+    }
+
+    #[test]
+    fn be_ok_failure_messages() {
+        assert_eq!(
+            be_ok(anything()).failure_message(&Err::<i32, String>(String::from("oops"))),
+            String::from("\tExpected:\n\t\tErr(\"oops\")\n\tto be Ok")
+        );
+        assert_eq!(
+            be_ok(equal(5)).negated_failure_message(&Ok::<i32, String>(5)),
+            String::from("\tExpected:\n\t\tOk(5)\n\tnot to be Ok")
+        );
+    }
+
+    #[test]
+    fn be_err_failure_messages() {
+        assert_eq!(
+            be_err(anything()).failure_message(&Ok::<i32, String>(5)),
+            String::from("\tExpected:\n\t\tOk(5)\n\tto be Err")
+        );
+    }
+}
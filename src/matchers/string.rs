@@ -0,0 +1,245 @@
+//! Matchers for `String` and `&str` values.
+
+use crate::matchers::with_diff;
+use crate::Matcher;
+
+/// Matches if `actual` starts with `prefix`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::start_with};
+/// expect(&"hello world").to(start_with("hello"));
+/// expect(&"hello world").not_to(start_with("world"));
+/// ```
+pub fn start_with<T: Into<String>>(prefix: T) -> StartWithMatcher {
+    StartWithMatcher {
+        prefix: prefix.into(),
+    }
+}
+
+pub struct StartWithMatcher {
+    prefix: String,
+}
+
+impl<A: AsRef<str> + std::fmt::Debug> Matcher<A> for StartWithMatcher {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref().starts_with(&self.prefix)
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        with_diff(
+            format!(
+                "\tExpected:\n\t\t{:?}\n\tto start with:\n\t\t{:?}",
+                actual, self.prefix
+            ),
+            &leading(actual.as_ref(), self.prefix.chars().count()),
+            &self.prefix,
+        )
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tnot to start with:\n\t\t{:?}",
+            actual, self.prefix
+        )
+    }
+}
+
+/// Matches if `actual` ends with `suffix`.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::end_with};
+/// expect(&"hello world").to(end_with("world"));
+/// expect(&"hello world").not_to(end_with("hello"));
+/// ```
+pub fn end_with<T: Into<String>>(suffix: T) -> EndWithMatcher {
+    EndWithMatcher {
+        suffix: suffix.into(),
+    }
+}
+
+pub struct EndWithMatcher {
+    suffix: String,
+}
+
+impl<A: AsRef<str> + std::fmt::Debug> Matcher<A> for EndWithMatcher {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref().ends_with(&self.suffix)
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        with_diff(
+            format!(
+                "\tExpected:\n\t\t{:?}\n\tto end with:\n\t\t{:?}",
+                actual, self.suffix
+            ),
+            &trailing(actual.as_ref(), self.suffix.chars().count()),
+            &self.suffix,
+        )
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tnot to end with:\n\t\t{:?}",
+            actual, self.suffix
+        )
+    }
+}
+
+/// Matches if `actual` contains `needle` as a substring.
+///
+/// # Examples
+///
+/// ```
+/// # use expect::{expect, matchers::string::contain_substring};
+/// expect(&"hello world").to(contain_substring("lo wo"));
+/// ```
+pub fn contain_substring<T: Into<String>>(needle: T) -> ContainSubstringMatcher {
+    ContainSubstringMatcher {
+        needle: needle.into(),
+    }
+}
+
+pub struct ContainSubstringMatcher {
+    needle: String,
+}
+
+impl<A: AsRef<str> + std::fmt::Debug> Matcher<A> for ContainSubstringMatcher {
+    fn match_value(&self, actual: &A) -> bool {
+        actual.as_ref().contains(&self.needle)
+    }
+
+    fn failure_message(&self, actual: &A) -> String {
+        with_diff(
+            format!(
+                "\tExpected:\n\t\t{:?}\n\tto contain:\n\t\t{:?}",
+                actual, self.needle
+            ),
+            &closest_window(actual.as_ref(), &self.needle),
+            &self.needle,
+        )
+    }
+
+    fn negated_failure_message(&self, actual: &A) -> String {
+        format!(
+            "\tExpected:\n\t\t{:?}\n\tnot to contain:\n\t\t{:?}",
+            actual, self.needle
+        )
+    }
+}
+
+/// Takes up to the first `len` characters of `value`, used so
+/// `start_with`'s diff lines up with `prefix` instead of trailing off into
+/// the unrelated rest of `value`.
+fn leading(value: &str, len: usize) -> String {
+    value.chars().take(len).collect()
+}
+
+/// Takes up to the last `len` characters of `value`, the `end_with`
+/// counterpart of [`leading`].
+fn trailing(value: &str, len: usize) -> String {
+    let total = value.chars().count();
+    value.chars().skip(total.saturating_sub(len)).collect()
+}
+
+/// Finds the substring of `value` the same length as `needle` with the
+/// fewest mismatched characters against it, so `contain_substring`'s diff
+/// highlights the closest near-miss instead of comparing against the whole
+/// (usually much longer) `value`.
+fn closest_window(value: &str, needle: &str) -> String {
+    let chars: Vec<char> = value.chars().collect();
+    let needle_chars: Vec<char> = needle.chars().collect();
+    let len = needle_chars.len();
+
+    if len == 0 || chars.len() <= len {
+        return value.to_string();
+    }
+
+    let best_start = (0..=chars.len() - len)
+        .min_by_key(|&start| {
+            chars[start..start + len]
+                .iter()
+                .zip(needle_chars.iter())
+                .filter(|(a, b)| a != b)
+                .count()
+        })
+        .unwrap_or(0);
+
+    chars[best_start..best_start + len].iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{contain_substring, end_with, start_with};
+    use crate::Matcher;
+
+    #[test]
+    fn start_with_matcher_should_match_if_actual_starts_with_prefix() {
+        assert!(start_with("hello").match_value(&"hello world"))
+    }
+
+    #[test]
+    fn start_with_matcher_should_not_match_if_actual_does_not_start_with_prefix() {
+        assert!(!start_with("world").match_value(&"hello world"))
+    }
+
+    #[test]
+    fn end_with_matcher_should_match_if_actual_ends_with_suffix() {
+        assert!(end_with("world").match_value(&"hello world"))
+    }
+
+    #[test]
+    fn end_with_matcher_should_not_match_if_actual_does_not_end_with_suffix() {
+        assert!(!end_with("hello").match_value(&"hello world"))
+    }
+
+    #[test]
+    fn contain_substring_matcher_should_match_if_actual_contains_needle() {
+        assert!(contain_substring("lo wo").match_value(&"hello world"))
+    }
+
+    #[test]
+    fn contain_substring_matcher_should_not_match_if_actual_does_not_contain_needle() {
+        assert!(!contain_substring("xyz").match_value(&"hello world"))
+    }
+
+    #[test]
+    fn contain_substring_matcher_failure_messages() {
+        let message = contain_substring("xyz").failure_message(&"hello world");
+        assert!(message
+            .starts_with("\tExpected:\n\t\t\"hello world\"\n\tto contain:\n\t\t\"xyz\"\n\tDiff:\n"));
+    }
+
+    #[test]
+    fn contain_substring_matcher_diffs_against_the_closest_matching_window() {
+        let message = contain_substring("wormd").failure_message(&"hello world");
+        assert!(message.contains("\t  - l"));
+        assert!(message.contains("\t  + m"));
+    }
+
+    #[test]
+    fn start_with_matcher_failure_message_includes_a_diff() {
+        let message = start_with("world").failure_message(&"hello world");
+        assert!(message.starts_with(
+            "\tExpected:\n\t\t\"hello world\"\n\tto start with:\n\t\t\"world\"\n\tDiff:\n"
+        ));
+    }
+
+    #[test]
+    fn start_with_matcher_diffs_by_character_count_not_byte_length_for_non_ascii_prefixes() {
+        let message = start_with("xéllo").failure_message(&"héllo world");
+        assert!(message.contains("\t  - h"));
+        assert!(message.contains("\t  + x"));
+        assert!(!message.contains("\t  - \" \""));
+    }
+
+    #[test]
+    fn end_with_matcher_diffs_by_character_count_not_byte_length_for_non_ascii_suffixes() {
+        let message = end_with("worléx").failure_message(&"hello worléd");
+        assert!(message.contains("\t  - d"));
+        assert!(message.contains("\t  + x"));
+    }
+}